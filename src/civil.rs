@@ -0,0 +1,23 @@
+//! Calendar math shared by rotation and timestamp rendering.
+
+/// Howard Hinnant's civil-from-days algorithm: proleptic Gregorian
+/// year/month/day from the number of days since 1970-01-01.
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = (z - era * 146_097) as u64;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let y = if m <= 2 { y + 1 } else { y };
+    (y, m, d)
+}
+
+/// The UTC calendar day, as `YYYY-MM-DD`, for a Unix timestamp in milliseconds.
+pub(crate) fn day_bucket(timestamp_millis: u64) -> String {
+    let (y, m, d) = civil_from_days((timestamp_millis / 86_400_000) as i64);
+    format!("{y:04}-{m:02}-{d:02}")
+}
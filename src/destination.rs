@@ -0,0 +1,52 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::Sink;
+
+/// Where logs should be written, selected by a single string — e.g. a config
+/// value or environment variable. `"-"`/`"stdout"` selects stdout, `"stderr"`
+/// selects stderr, and anything else is treated as a file path.
+///
+/// Use [`crate::from_env`] to wire a destination up from the environment
+/// without branching on each `append`/`truncate` helper yourself.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Destination {
+    Stdout,
+    Stderr,
+    File(PathBuf),
+}
+
+/// Whether a [`Destination::File`] is opened in append or truncate mode.
+/// Ignored by `Stdout`/`Stderr`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    Append,
+    Truncate,
+}
+
+impl FromStr for Destination {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "-" | "stdout" => Destination::Stdout,
+            "stderr" => Destination::Stderr,
+            other => Destination::File(PathBuf::from(other)),
+        })
+    }
+}
+
+impl Destination {
+    /// Turn this destination into a [`Sink`], opening a `File` destination
+    /// according to `mode`.
+    pub fn into_sink(self, mode: Mode) -> std::io::Result<Sink> {
+        match self {
+            Destination::Stdout => Ok(Sink::stdout()),
+            Destination::Stderr => Ok(Sink::stderr()),
+            Destination::File(path) => match mode {
+                Mode::Append => Sink::append(path),
+                Mode::Truncate => Sink::truncate(path),
+            },
+        }
+    }
+}
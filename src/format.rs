@@ -0,0 +1,142 @@
+use std::time::Instant;
+
+/// How a record's timestamp should be rendered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimestampFormat {
+    /// Raw Unix time in milliseconds. This is the original, always-available
+    /// format and requires no wall-clock conversion.
+    UnixMillis,
+    /// An RFC3339 UTC timestamp: `YYYY-MM-DDTHH:MM:SS.mmmZ`.
+    UtcWallClock,
+    /// Milliseconds elapsed since the logger was initialized.
+    MonotonicMillis,
+}
+
+/// A single piece of a formatted record.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Field {
+    Level,
+    Timestamp,
+    Target,
+    Message,
+}
+
+/// Formatting knobs for a [`crate::FileLogger`].
+///
+/// Use [`Options::new`] and its builder methods to customize the timestamp
+/// rendering, which fields are emitted (and in what order), and whether
+/// ANSI color codes are added for the level field.
+#[derive(Clone, Debug)]
+pub struct Options {
+    pub(crate) timestamp: TimestampFormat,
+    pub(crate) fields: Vec<Field>,
+    pub(crate) color: bool,
+    pub(crate) store: Option<crate::store::StoreConfig>,
+    pub(crate) flush: crate::FlushPolicy,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            timestamp: TimestampFormat::UnixMillis,
+            fields: vec![
+                Field::Level,
+                Field::Timestamp,
+                Field::Target,
+                Field::Message,
+            ],
+            color: false,
+            store: None,
+            flush: crate::FlushPolicy::default(),
+        }
+    }
+}
+
+impl Options {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Choose how the timestamp field is rendered.
+    pub fn timestamp(mut self, format: TimestampFormat) -> Self {
+        self.timestamp = format;
+        self
+    }
+
+    /// Choose which fields are emitted, and in what order.
+    pub fn fields(mut self, fields: Vec<Field>) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    /// Emit ANSI color codes around the level field, for `tail -f`-ing the file.
+    pub fn color(mut self, enabled: bool) -> Self {
+        self.color = enabled;
+        self
+    }
+
+    /// Keep a queryable in-memory copy of recent records alongside the file sink.
+    pub fn store(mut self, config: crate::store::StoreConfig) -> Self {
+        self.store = Some(config);
+        self
+    }
+
+    /// Choose how often buffered sinks are flushed to disk. Defaults to
+    /// flushing after every record.
+    pub fn flush(mut self, policy: crate::FlushPolicy) -> Self {
+        self.flush = policy;
+        self
+    }
+}
+
+pub(crate) fn render(options: &Options, record: &log::Record, start: Instant) -> String {
+    let mut out = String::new();
+    for (i, field) in options.fields.iter().enumerate() {
+        if i > 0 {
+            out.push(' ');
+        }
+        match field {
+            Field::Level => render_level(&mut out, record.level(), options.color),
+            Field::Timestamp => render_timestamp(&mut out, options.timestamp, start),
+            Field::Target => out.push_str(&format!("[{}]", record.target())),
+            Field::Message => out.push_str(&record.args().to_string()),
+        }
+    }
+    out.push('\n');
+    out
+}
+
+fn render_level(out: &mut String, level: log::Level, color: bool) {
+    if !color {
+        out.push_str(&format!("[{level: <5}]"));
+        return;
+    }
+
+    let code = match level {
+        log::Level::Error => "31",
+        log::Level::Warn => "33",
+        log::Level::Info => "32",
+        log::Level::Debug => "36",
+        log::Level::Trace => "90",
+    };
+    out.push_str(&format!("\x1b[{code}m[{level: <5}]\x1b[0m"));
+}
+
+fn render_timestamp(out: &mut String, format: TimestampFormat, start: Instant) {
+    match format {
+        TimestampFormat::UnixMillis => out.push_str(&crate::timestamp().to_string()),
+        TimestampFormat::MonotonicMillis => out.push_str(&start.elapsed().as_millis().to_string()),
+        TimestampFormat::UtcWallClock => out.push_str(&utc_wall_clock()),
+    }
+}
+
+fn utc_wall_clock() -> String {
+    let millis = crate::timestamp();
+    let ms_of_day = millis % 86_400_000;
+    let (y, m, d) = crate::civil::civil_from_days((millis / 86_400_000) as i64);
+    let hour = ms_of_day / 3_600_000;
+    let minute = (ms_of_day / 60_000) % 60;
+    let second = (ms_of_day / 1_000) % 60;
+    let ms = ms_of_day % 1_000;
+    format!("{y:04}-{m:02}-{d:02}T{hour:02}:{minute:02}:{second:02}.{ms:03}Z")
+}
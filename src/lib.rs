@@ -1,7 +1,23 @@
-use std::io::Write;
-
 use std::error::Error;
 use std::path::Path;
+use std::sync::Mutex;
+use std::time::Instant;
+
+mod civil;
+mod destination;
+mod format;
+mod rotation;
+mod sink;
+mod store;
+mod writer;
+
+pub use destination::{Destination, Mode};
+pub use format::{Field, Options, TimestampFormat};
+use rotation::Rolling;
+pub use sink::Sink;
+pub use store::{query, Filter, StoreConfig, StoredRecord};
+pub use writer::FlushPolicy;
+use writer::{KeepOpenWriter, TransientWriter};
 
 type Result = std::result::Result<(), Box<dyn Error + Send + Sync + 'static>>;
 
@@ -12,8 +28,9 @@ type Result = std::result::Result<(), Box<dyn Error + Send + Sync + 'static>>;
 /// * open and then the file for each write
 pub fn append_transient(path: impl AsRef<Path>) -> Result {
     init(
-        Kind::Transient(path.as_ref().to_path_buf()),
+        vec![Sink::append_transient(path)],
         log_filter_parse::Filters::from_env(),
+        Options::default(),
     )
 }
 
@@ -23,10 +40,10 @@ pub fn append_transient(path: impl AsRef<Path>) -> Result {
 /// * truncate the file initially
 /// * open and then the file for each write
 pub fn truncate_transient(path: impl AsRef<Path>) -> Result {
-    let _ = std::fs::remove_file(path.as_ref());
     init(
-        Kind::Transient(path.as_ref().to_path_buf()),
+        vec![Sink::truncate_transient(path)],
         log_filter_parse::Filters::from_env(),
+        Options::default(),
     )
 }
 
@@ -37,8 +54,9 @@ pub fn truncate_transient(path: impl AsRef<Path>) -> Result {
 /// * keep the file open ('locked') until the process exists.
 pub fn append(path: impl AsRef<Path>) -> Result {
     init(
-        Kind::KeepOpen(std::fs::File::open(path)?),
+        vec![Sink::append(path)?],
         log_filter_parse::Filters::from_env(),
+        Options::default(),
     )
 }
 
@@ -49,54 +67,181 @@ pub fn append(path: impl AsRef<Path>) -> Result {
 /// * keep the file open ('locked') until the process exists.
 pub fn truncate(path: impl AsRef<Path>) -> Result {
     init(
-        Kind::KeepOpen(std::fs::File::open(path)?),
+        vec![Sink::truncate(path)?],
+        log_filter_parse::Filters::from_env(),
+        Options::default(),
+    )
+}
+
+/// Create the file logger at this path, with custom record formatting.
+///
+/// This will:
+/// * append to the file
+/// * keep the file open ('locked') until the process exists.
+pub fn append_with(path: impl AsRef<Path>, options: Options) -> Result {
+    init(
+        vec![Sink::append(path)?],
+        log_filter_parse::Filters::from_env(),
+        options,
+    )
+}
+
+/// Create the file logger at this path, with custom record formatting.
+///
+/// This will:
+/// * truncate the file initially
+/// * keep the file open ('locked') until the process exists.
+pub fn truncate_with(path: impl AsRef<Path>, options: Options) -> Result {
+    init(
+        vec![Sink::truncate(path)?],
         log_filter_parse::Filters::from_env(),
+        options,
     )
 }
 
-fn init(kind: Kind, filters: log_filter_parse::Filters) -> Result {
+/// Create the file logger at this path.
+///
+/// This will:
+/// * append to the file
+/// * rotate it to `path.1` (shifting older backups up, and dropping
+///   whatever falls off the end) once it grows past `max_bytes`
+/// * keep at most `keep` rotated backups
+pub fn rolling_size(path: impl AsRef<Path>, max_bytes: u64, keep: usize) -> Result {
+    init(
+        vec![Sink::rolling_size(path, max_bytes, keep)?],
+        log_filter_parse::Filters::from_env(),
+        Options::default(),
+    )
+}
+
+/// Create the file logger at this path.
+///
+/// This will:
+/// * append to the file
+/// * rotate it to `path.YYYY-MM-DD` (named for the day that just ended)
+///   whenever the UTC calendar day changes
+/// * keep at most `keep` dated backups, deleting the oldest beyond that
+pub fn rolling_daily(path: impl AsRef<Path>, keep: usize) -> Result {
+    init(
+        vec![Sink::rolling_daily(path, keep)?],
+        log_filter_parse::Filters::from_env(),
+        Options::default(),
+    )
+}
+
+/// Create the file logger from the destination named by the environment
+/// variable `var_name` (see [`Destination`]), appending to file destinations.
+///
+/// This lets an application wire up logging purely from its environment,
+/// without branching on each `append`/`truncate` helper itself.
+pub fn from_env(var_name: &str) -> Result {
+    from_env_with(var_name, Mode::Append)
+}
+
+/// Create the file logger from the destination named by the environment
+/// variable `var_name` (see [`Destination`]), opening a file destination
+/// according to `mode`.
+pub fn from_env_with(var_name: &str, mode: Mode) -> Result {
+    let value = std::env::var(var_name)?;
+    let Ok(destination) = value.parse::<Destination>();
+    init(
+        vec![destination.into_sink(mode)?],
+        log_filter_parse::Filters::from_env(),
+        Options::default(),
+    )
+}
+
+/// Build a logger that fans out to multiple [`Sink`]s, each with an optional
+/// per-sink level threshold (e.g. everything to one file, only `WARN`+ to
+/// another).
+#[derive(Default)]
+pub struct Builder {
+    sinks: Vec<Sink>,
+    filters: Option<log_filter_parse::Filters>,
+    options: Options,
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a destination to write records to.
+    pub fn sink(mut self, sink: Sink) -> Self {
+        self.sinks.push(sink);
+        self
+    }
+
+    /// Override the level/target filters. Defaults to `Filters::from_env()`.
+    pub fn filters(mut self, filters: log_filter_parse::Filters) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    /// Override the record formatting and store options.
+    pub fn options(mut self, options: Options) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Install the logger.
+    pub fn init(self) -> Result {
+        init(
+            self.sinks,
+            self.filters
+                .unwrap_or_else(log_filter_parse::Filters::from_env),
+            self.options,
+        )
+    }
+}
+
+fn init(sinks: Vec<Sink>, filters: log_filter_parse::Filters, options: Options) -> Result {
+    let store = options.store.clone().map(store::Store::install);
+
     log::set_max_level(log::LevelFilter::Trace);
-    log::set_boxed_logger(Box::new(FileLogger { kind, filters }))?;
+    log::set_boxed_logger(Box::new(FileLogger {
+        sinks,
+        filters,
+        options,
+        start: Instant::now(),
+        store,
+    }))?;
     Ok(())
 }
 
 struct FileLogger {
-    kind: Kind,
+    sinks: Vec<Sink>,
     filters: log_filter_parse::Filters,
+    options: Options,
+    start: Instant,
+    store: Option<std::sync::Arc<store::Store>>,
 }
 
 impl FileLogger {
     fn print(&self, record: &log::Record) {
-        let (mut file, mut new);
+        let payload = format::render(&self.options, record, self.start);
 
-        let write: &mut dyn std::io::Write = match &self.kind {
-            Kind::KeepOpen(fi) => {
-                file = fi;
-                &mut file
+        for sink in &self.sinks {
+            if sink.accepts(record.level()) {
+                write_to_kind(&sink.kind, &payload, self.options.flush);
             }
-            Kind::Transient(path) => {
-                match std::fs::OpenOptions::new()
-                    .write(true)
-                    .create(true)
-                    .append(true)
-                    .open(path)
-                {
-                    Ok(fi) => {
-                        new = fi;
-                        &mut new
-                    }
-                    Err(..) => return,
-                }
-            }
-        };
-
-        let _ = { write }.write_fmt(format_args!(
-            "[{level: <5}] {timestamp} [{target}] {payload}",
-            level = record.level(),
-            timestamp = timestamp(),
-            target = record.target(),
-            payload = record.args(),
-        ));
+        }
+    }
+}
+
+fn write_to_kind(kind: &Kind, payload: &str, flush: FlushPolicy) {
+    use std::io::Write as _;
+
+    match kind {
+        Kind::KeepOpen(writer) => writer.write(payload, flush),
+        Kind::Transient(writer) => writer.write(payload),
+        Kind::Rolling(rolling) => Rolling::write(rolling, payload.as_bytes()),
+        Kind::Stdout => {
+            let _ = std::io::stdout().lock().write_all(payload.as_bytes());
+        }
+        Kind::Stderr => {
+            let _ = std::io::stderr().lock().write_all(payload.as_bytes());
+        }
     }
 }
 
@@ -109,21 +254,43 @@ impl log::Log for FileLogger {
     #[inline]
     fn log(&self, record: &log::Record) {
         if self.enabled(record.metadata()) {
-            self.print(record)
+            self.print(record);
+            if let Some(store) = &self.store {
+                store.push(store::StoredRecord {
+                    level: record.level(),
+                    target: record.target().to_string(),
+                    timestamp: timestamp(),
+                    message: record.args().to_string(),
+                });
+            }
         }
     }
 
     #[inline]
     fn flush(&self) {
-        if let Kind::KeepOpen(file) = &self.kind {
-            let _ = { file }.flush();
+        use std::io::Write as _;
+
+        for sink in &self.sinks {
+            match &sink.kind {
+                Kind::KeepOpen(writer) => writer.flush(),
+                Kind::Stdout => {
+                    let _ = std::io::stdout().lock().flush();
+                }
+                Kind::Stderr => {
+                    let _ = std::io::stderr().lock().flush();
+                }
+                Kind::Transient(..) | Kind::Rolling(..) => {}
+            }
         }
     }
 }
 
-enum Kind {
-    KeepOpen(std::fs::File),
-    Transient(std::path::PathBuf),
+pub(crate) enum Kind {
+    KeepOpen(KeepOpenWriter),
+    Transient(TransientWriter),
+    Rolling(Mutex<Rolling>),
+    Stdout,
+    Stderr,
 }
 
 fn timestamp() -> u64 {
@@ -0,0 +1,184 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// What triggers a rotation of the active log file.
+enum Trigger {
+    /// Rotate once the active file has grown past this many bytes.
+    Size(u64),
+    /// Rotate once the calendar day (UTC) changes.
+    Daily,
+}
+
+/// The mutable, lockable state behind [`crate::Kind::Rolling`].
+pub(crate) struct Rolling {
+    path: PathBuf,
+    file: File,
+    keep: usize,
+    trigger: Trigger,
+    written: u64,
+    bucket: String,
+}
+
+impl Rolling {
+    pub(crate) fn size(path: PathBuf, max_bytes: u64, keep: usize) -> std::io::Result<Self> {
+        let file = open(&path)?;
+        let written = file.metadata().map(|md| md.len()).unwrap_or(0);
+        Ok(Self {
+            path,
+            file,
+            keep,
+            trigger: Trigger::Size(max_bytes),
+            written,
+            bucket: String::new(),
+        })
+    }
+
+    pub(crate) fn daily(path: PathBuf, keep: usize) -> std::io::Result<Self> {
+        let file = open(&path)?;
+        Ok(Self {
+            path,
+            file,
+            keep,
+            trigger: Trigger::Daily,
+            written: 0,
+            bucket: crate::civil::day_bucket(crate::timestamp()),
+        })
+    }
+
+    pub(crate) fn write(this: &Mutex<Self>, payload: &[u8]) {
+        let mut this = match this.lock() {
+            Ok(this) => this,
+            Err(..) => return,
+        };
+
+        if let Trigger::Daily = this.trigger {
+            let bucket = crate::civil::day_bucket(crate::timestamp());
+            if this.bucket != bucket {
+                let ending_bucket = std::mem::replace(&mut this.bucket, bucket);
+                let _ = this.rotate_daily(&ending_bucket);
+            }
+        }
+
+        if this.file.write_all(payload).is_ok() {
+            this.written += payload.len() as u64;
+        }
+
+        if let Trigger::Size(max_bytes) = this.trigger {
+            if this.written > max_bytes {
+                let _ = this.rotate_numbered();
+            }
+        }
+    }
+
+    /// Close the active file, shift `path.1 .. path.(keep-1)` up by one
+    /// (dropping whatever falls off the end), move the active file to
+    /// `path.1`, then open a fresh file at `path`. With `keep == 0` there are
+    /// no backups to keep, so the active file is truncated in place instead.
+    fn rotate_numbered(&mut self) -> std::io::Result<()> {
+        if self.keep == 0 {
+            return self.reopen_truncated();
+        }
+
+        let _ = std::fs::remove_file(self.numbered_backup_path(self.keep));
+        for index in (1..self.keep).rev() {
+            let from = self.numbered_backup_path(index);
+            let to = self.numbered_backup_path(index + 1);
+            let _ = std::fs::rename(from, to);
+        }
+        let _ = std::fs::rename(&self.path, self.numbered_backup_path(1));
+
+        self.reopen()
+    }
+
+    /// Close the active file, move it to `path.YYYY-MM-DD` for the day that
+    /// just ended, then open a fresh file at `path` and prune dated backups
+    /// beyond `keep` (with `keep == 0`, every dated backup is pruned).
+    fn rotate_daily(&mut self, ending_bucket: &str) -> std::io::Result<()> {
+        let backup = self.dated_backup_path(ending_bucket);
+        let _ = std::fs::remove_file(&backup);
+        let _ = std::fs::rename(&self.path, &backup);
+        self.prune_dated_backups();
+
+        self.reopen()
+    }
+
+    fn reopen(&mut self) -> std::io::Result<()> {
+        self.file = open(&self.path)?;
+        self.written = 0;
+        Ok(())
+    }
+
+    fn reopen_truncated(&mut self) -> std::io::Result<()> {
+        self.file = std::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&self.path)?;
+        self.written = 0;
+        Ok(())
+    }
+
+    fn numbered_backup_path(&self, index: usize) -> PathBuf {
+        let mut name = self.path.as_os_str().to_owned();
+        name.push(format!(".{index}"));
+        PathBuf::from(name)
+    }
+
+    fn dated_backup_path(&self, bucket: &str) -> PathBuf {
+        let mut name = self.path.as_os_str().to_owned();
+        name.push(format!(".{bucket}"));
+        PathBuf::from(name)
+    }
+
+    fn prune_dated_backups(&self) {
+        let Some(file_name) = self.path.file_name().and_then(|n| n.to_str()) else {
+            return;
+        };
+        let dir = match self.path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+
+        let prefix = format!("{file_name}.");
+        let mut backups: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|n| n.to_str())
+                    .and_then(|n| n.strip_prefix(&prefix))
+                    .is_some_and(is_date_bucket)
+            })
+            .collect();
+
+        // `YYYY-MM-DD` suffixes sort lexicographically in chronological order.
+        backups.sort();
+        while backups.len() > self.keep {
+            let _ = std::fs::remove_file(backups.remove(0));
+        }
+    }
+}
+
+fn is_date_bucket(suffix: &str) -> bool {
+    let bytes = suffix.as_bytes();
+    bytes.len() == 10
+        && bytes[4] == b'-'
+        && bytes[7] == b'-'
+        && bytes
+            .iter()
+            .enumerate()
+            .all(|(i, b)| i == 4 || i == 7 || b.is_ascii_digit())
+}
+
+fn open(path: &Path) -> std::io::Result<File> {
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .append(true)
+        .open(path)
+}
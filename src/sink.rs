@@ -0,0 +1,103 @@
+use std::path::Path;
+use std::sync::Mutex;
+
+use crate::rotation::Rolling;
+use crate::writer::{KeepOpenWriter, TransientWriter};
+use crate::Kind;
+
+/// A single log destination, with an optional per-sink level threshold.
+///
+/// Use one of the constructors (mirroring the crate's free functions, e.g.
+/// [`Sink::append`]) and [`Sink::level`] to restrict what it receives, then
+/// add it to a [`crate::Builder`].
+pub struct Sink {
+    pub(crate) kind: Kind,
+    pub(crate) level: Option<log::LevelFilter>,
+}
+
+impl Sink {
+    /// Append to `path`, opening a fresh handle for each record.
+    pub fn append_transient(path: impl AsRef<Path>) -> Self {
+        Self::from_kind(Kind::Transient(TransientWriter::new(
+            path.as_ref().to_path_buf(),
+        )))
+    }
+
+    /// Truncate `path` initially, then open a fresh handle for each record.
+    pub fn truncate_transient(path: impl AsRef<Path>) -> Self {
+        let _ = std::fs::remove_file(path.as_ref());
+        Self::from_kind(Kind::Transient(TransientWriter::new(
+            path.as_ref().to_path_buf(),
+        )))
+    }
+
+    /// Append to `path`, keeping it open ('locked') until the process exits.
+    pub fn append(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let file = std::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .append(true)
+            .open(path)?;
+        Ok(Self::from_kind(Kind::KeepOpen(KeepOpenWriter::new(file))))
+    }
+
+    /// Truncate `path` initially, then keep it open ('locked') until the process exits.
+    pub fn truncate(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let file = std::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        Ok(Self::from_kind(Kind::KeepOpen(KeepOpenWriter::new(file))))
+    }
+
+    /// Append to `path`, rotating it to `path.1` once it grows past `max_bytes`
+    /// and keeping at most `keep` rotated backups.
+    pub fn rolling_size(
+        path: impl AsRef<Path>,
+        max_bytes: u64,
+        keep: usize,
+    ) -> std::io::Result<Self> {
+        Ok(Self::from_kind(Kind::Rolling(Mutex::new(Rolling::size(
+            path.as_ref().to_path_buf(),
+            max_bytes,
+            keep,
+        )?))))
+    }
+
+    /// Append to `path`, rotating it to `path.YYYY-MM-DD` whenever the UTC
+    /// calendar day changes and keeping at most `keep` dated backups.
+    pub fn rolling_daily(path: impl AsRef<Path>, keep: usize) -> std::io::Result<Self> {
+        Ok(Self::from_kind(Kind::Rolling(Mutex::new(Rolling::daily(
+            path.as_ref().to_path_buf(),
+            keep,
+        )?))))
+    }
+
+    /// Write to stdout.
+    pub fn stdout() -> Self {
+        Self::from_kind(Kind::Stdout)
+    }
+
+    /// Write to stderr.
+    pub fn stderr() -> Self {
+        Self::from_kind(Kind::Stderr)
+    }
+
+    fn from_kind(kind: Kind) -> Self {
+        Self { kind, level: None }
+    }
+
+    /// Only write records at or above this level to this sink.
+    pub fn level(mut self, level: log::LevelFilter) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    pub(crate) fn accepts(&self, level: log::Level) -> bool {
+        match self.level {
+            Some(filter) => level <= filter,
+            None => true,
+        }
+    }
+}
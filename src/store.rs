@@ -0,0 +1,176 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use regex::Regex;
+
+/// A single record captured by the in-memory store.
+#[derive(Clone, Debug)]
+pub struct StoredRecord {
+    pub level: log::Level,
+    pub target: String,
+    pub timestamp: u64,
+    pub message: String,
+}
+
+/// Configuration for the optional in-memory log store.
+#[derive(Clone, Debug)]
+pub struct StoreConfig {
+    pub(crate) max_count: usize,
+    pub(crate) retention: Option<Duration>,
+}
+
+impl Default for StoreConfig {
+    fn default() -> Self {
+        Self {
+            max_count: 1_000,
+            retention: None,
+        }
+    }
+}
+
+impl StoreConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Evict the oldest records once more than this many are held. Defaults to 1000.
+    pub fn max_count(mut self, max_count: usize) -> Self {
+        self.max_count = max_count;
+        self
+    }
+
+    /// Evict records older than this duration, in addition to `max_count`.
+    pub fn retention(mut self, retention: Duration) -> Self {
+        self.retention = Some(retention);
+        self
+    }
+}
+
+pub(crate) struct Store {
+    config: StoreConfig,
+    records: Mutex<VecDeque<StoredRecord>>,
+}
+
+static STORE: OnceLock<Arc<Store>> = OnceLock::new();
+
+impl Store {
+    pub(crate) fn install(config: StoreConfig) -> Arc<Self> {
+        let store = Arc::new(Self {
+            config,
+            records: Mutex::new(VecDeque::new()),
+        });
+        // `OnceLock::set` only succeeds on the first call, so only the first
+        // initialized logger's store is queryable via [`query`]; later stores
+        // still work for direct record-keeping but [`query`] won't see them.
+        let _ = STORE.set(store.clone());
+        store
+    }
+
+    pub(crate) fn push(&self, record: StoredRecord) {
+        let Ok(mut records) = self.records.lock() else {
+            return;
+        };
+
+        records.push_back(record);
+
+        if let Some(retention) = self.config.retention {
+            let cutoff = crate::timestamp().saturating_sub(retention.as_millis() as u64);
+            while records.front().is_some_and(|r| r.timestamp < cutoff) {
+                records.pop_front();
+            }
+        }
+
+        while records.len() > self.config.max_count {
+            records.pop_front();
+        }
+    }
+}
+
+/// What to match when [`query`]ing the in-memory store.
+#[derive(Clone, Debug, Default)]
+pub struct Filter {
+    min_level: Option<log::LevelFilter>,
+    target: Option<String>,
+    message: Option<Regex>,
+    not_before: Option<u64>,
+    limit: Option<usize>,
+}
+
+impl Filter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match records at or above this level.
+    pub fn min_level(mut self, level: log::LevelFilter) -> Self {
+        self.min_level = Some(level);
+        self
+    }
+
+    /// Only match records whose target contains this substring.
+    pub fn target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    /// Only match records whose message matches this regex.
+    pub fn message(mut self, pattern: Regex) -> Self {
+        self.message = Some(pattern);
+        self
+    }
+
+    /// Only match records at or after this Unix timestamp, in milliseconds.
+    pub fn not_before(mut self, timestamp: u64) -> Self {
+        self.not_before = Some(timestamp);
+        self
+    }
+
+    /// Cap the number of records returned.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    fn matches(&self, record: &StoredRecord) -> bool {
+        if let Some(min_level) = self.min_level {
+            if record.level > min_level {
+                return false;
+            }
+        }
+        if let Some(target) = &self.target {
+            if !record.target.contains(target.as_str()) {
+                return false;
+            }
+        }
+        if let Some(message) = &self.message {
+            if !message.is_match(&record.message) {
+                return false;
+            }
+        }
+        if let Some(not_before) = self.not_before {
+            if record.timestamp < not_before {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Query the in-memory log store installed via [`crate::Options::store`], newest-first.
+///
+/// Returns an empty `Vec` if no store was installed.
+pub fn query(filter: Filter) -> Vec<StoredRecord> {
+    let Some(store) = STORE.get() else {
+        return Vec::new();
+    };
+    let Ok(records) = store.records.lock() else {
+        return Vec::new();
+    };
+
+    let matches = records.iter().rev().filter(|r| filter.matches(r)).cloned();
+    match filter.limit {
+        Some(limit) => matches.take(limit).collect(),
+        None => matches.collect(),
+    }
+}
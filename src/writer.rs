@@ -0,0 +1,110 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How often a [`crate::Sink`]'s buffered writer is flushed to disk.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FlushPolicy {
+    /// Flush after every record. Safest, but pays a syscall per record.
+    EveryRecord,
+    /// Flush no more often than this interval; call `log::logger().flush()`
+    /// for anything tighter (e.g. before the process exits).
+    Interval(Duration),
+}
+
+impl Default for FlushPolicy {
+    fn default() -> Self {
+        FlushPolicy::EveryRecord
+    }
+}
+
+/// A shared, mutex-guarded [`BufWriter`] for a file kept open for the
+/// lifetime of the logger. Serializes whole records so concurrent writers
+/// can't interleave partial lines, and batches syscalls according to the
+/// active [`FlushPolicy`].
+pub(crate) struct KeepOpenWriter {
+    inner: Mutex<KeepOpenInner>,
+}
+
+struct KeepOpenInner {
+    writer: BufWriter<File>,
+    last_flush: Instant,
+}
+
+impl KeepOpenWriter {
+    pub(crate) fn new(file: File) -> Self {
+        Self {
+            inner: Mutex::new(KeepOpenInner {
+                writer: BufWriter::new(file),
+                last_flush: Instant::now(),
+            }),
+        }
+    }
+
+    pub(crate) fn write(&self, payload: &str, policy: FlushPolicy) {
+        let Ok(mut inner) = self.inner.lock() else {
+            return;
+        };
+
+        if inner.writer.write_all(payload.as_bytes()).is_err() {
+            return;
+        }
+
+        match policy {
+            FlushPolicy::EveryRecord => {
+                let _ = inner.writer.flush();
+                inner.last_flush = Instant::now();
+            }
+            FlushPolicy::Interval(interval) => {
+                if inner.last_flush.elapsed() >= interval {
+                    let _ = inner.writer.flush();
+                    inner.last_flush = Instant::now();
+                }
+            }
+        }
+    }
+
+    pub(crate) fn flush(&self) {
+        if let Ok(mut inner) = self.inner.lock() {
+            let _ = inner.writer.flush();
+            inner.last_flush = Instant::now();
+        }
+    }
+}
+
+/// Reopens the destination file for every record (no handle is held between
+/// writes), but guards the open-write-close sequence with a mutex so a
+/// single record is still emitted atomically under concurrent writers.
+pub(crate) struct TransientWriter {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl TransientWriter {
+    pub(crate) fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            lock: Mutex::new(()),
+        }
+    }
+
+    pub(crate) fn write(&self, payload: &str) {
+        let Ok(_guard) = self.lock.lock() else {
+            return;
+        };
+        let Ok(mut file) = open(&self.path) else {
+            return;
+        };
+        let _ = file.write_all(payload.as_bytes());
+    }
+}
+
+fn open(path: &Path) -> std::io::Result<File> {
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .append(true)
+        .open(path)
+}